@@ -43,6 +43,159 @@ pub trait Signer {
     fn ocsp_val(&self) -> Option<Vec<u8>> {
         None
     }
+
+    /// Returns `true` if `sign` expects to be given a message digest rather
+    /// than the raw to-be-signed bytes.
+    ///
+    /// Some HSMs and KMS backends only expose a `sign(digest)` operation, so
+    /// they cannot hash the full COSE `Sig_structure` themselves. When this
+    /// returns `true`, [`prepare_to_sign`] hashes the to-be-signed bytes
+    /// with [`Signer::digest_alg`] instead of passing the raw bytes through.
+    /// The hashing happens only where the caller runs the bytes through
+    /// [`prepare_to_sign`]; `sign` itself is not wrapped.
+    fn direct_cose_handling(&self) -> bool {
+        false
+    }
+
+    /// The digest algorithm the signer expects to receive when
+    /// [`Signer::direct_cose_handling`] returns `true`, e.g. `"sha256"`.
+    ///
+    /// Must be consistent with [`Signer::alg`] (for example `ES256` requires
+    /// `sha256`); [`validate_digest_alg`] enforces this.
+    fn digest_alg(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Validates that `digest_alg` is the digest algorithm mandated for
+/// `signing_alg` by the C2PA specification (e.g. `ES256` requires `sha256`),
+/// returning an error on any mismatch.
+pub fn validate_digest_alg(signing_alg: &str, digest_alg: &str) -> Result<()> {
+    let expected = match signing_alg.to_ascii_uppercase().as_str() {
+        "ES256" | "PS256" => "sha256",
+        "ES384" | "PS384" => "sha384",
+        "ES512" | "PS512" => "sha512",
+        _ => return Err(crate::Error::UnsupportedType),
+    };
+
+    if digest_alg.to_ascii_lowercase() != expected {
+        return Err(crate::Error::UnsupportedType);
+    }
+
+    Ok(())
+}
+
+/// Prepares the to-be-signed COSE bytes for `signer.sign`.
+///
+/// When [`Signer::direct_cose_handling`] is `false` (the common case),
+/// `data` is returned unchanged. When it's `true`, this validates
+/// [`Signer::digest_alg`] against [`Signer::alg`] via [`validate_digest_alg`]
+/// and returns the digest of `data` instead, so `sign` receives only the
+/// digest the signer expects rather than the raw bytes.
+///
+/// Callers building the to-be-signed COSE bytes should run them through this
+/// before calling `signer.sign`.
+pub fn prepare_to_sign(signer: &dyn Signer, data: &[u8]) -> Result<Vec<u8>> {
+    if !signer.direct_cose_handling() {
+        return Ok(data.to_vec());
+    }
+
+    let signing_alg = signer.alg().ok_or(crate::Error::UnsupportedType)?;
+    let digest_alg = signer.digest_alg().ok_or(crate::Error::UnsupportedType)?;
+    validate_digest_alg(&signing_alg, &digest_alg)?;
+
+    digest_with(&digest_alg, data)
+}
+
+/// Hashes `data` with the digest algorithm named by `digest_alg` (`"sha256"`,
+/// `"sha384"`, or `"sha512"`).
+fn digest_with(digest_alg: &str, data: &[u8]) -> Result<Vec<u8>> {
+    use sha2::Digest;
+
+    Ok(match digest_alg.to_ascii_lowercase().as_str() {
+        "sha256" => sha2::Sha256::digest(data).to_vec(),
+        "sha384" => sha2::Sha384::digest(data).to_vec(),
+        "sha512" => sha2::Sha512::digest(data).to_vec(),
+        _ => return Err(crate::Error::UnsupportedType),
+    })
+}
+
+#[cfg(test)]
+mod digest_signing_tests {
+    use sha2::Digest;
+
+    use super::*;
+
+    struct DigestSigner {
+        alg: &'static str,
+        digest_alg: &'static str,
+    }
+
+    impl Signer for DigestSigner {
+        fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+            Ok(data.to_vec())
+        }
+
+        fn alg(&self) -> Option<String> {
+            Some(self.alg.to_string())
+        }
+
+        fn certs(&self) -> Result<Vec<Vec<u8>>> {
+            Ok(Vec::new())
+        }
+
+        fn reserve_size(&self) -> usize {
+            128
+        }
+
+        fn direct_cose_handling(&self) -> bool {
+            true
+        }
+
+        fn digest_alg(&self) -> Option<String> {
+            Some(self.digest_alg.to_string())
+        }
+    }
+
+    #[test]
+    fn validate_digest_alg_accepts_matching_pairs() {
+        assert!(validate_digest_alg("ES256", "sha256").is_ok());
+        assert!(validate_digest_alg("es384", "SHA384").is_ok());
+        assert!(validate_digest_alg("PS512", "sha512").is_ok());
+    }
+
+    #[test]
+    fn validate_digest_alg_rejects_mismatched_pairs() {
+        assert!(validate_digest_alg("ES256", "sha384").is_err());
+        assert!(validate_digest_alg("ES512", "sha256").is_err());
+    }
+
+    #[test]
+    fn prepare_to_sign_passes_raw_bytes_when_not_direct() {
+        let signer = Placeholder {};
+        assert_eq!(prepare_to_sign(&signer, b"claim bytes").unwrap(), b"claim bytes");
+    }
+
+    #[test]
+    fn prepare_to_sign_hashes_for_direct_cose_handling() {
+        let signer = DigestSigner {
+            alg: "ES256",
+            digest_alg: "sha256",
+        };
+
+        let digest = prepare_to_sign(&signer, b"claim bytes").unwrap();
+        assert_eq!(digest, sha2::Sha256::digest(b"claim bytes").to_vec());
+    }
+
+    #[test]
+    fn prepare_to_sign_rejects_mismatched_digest_alg() {
+        let signer = DigestSigner {
+            alg: "ES256",
+            digest_alg: "sha512",
+        };
+
+        assert!(prepare_to_sign(&signer, b"claim bytes").is_err());
+    }
 }
 
 /// Trait to allow loading of signing credential from external sources
@@ -91,6 +244,93 @@ impl Signer for Placeholder {
     }
 }
 
+/// The signing callback held by a [`CallbackSigner`].
+type CallbackFunc = dyn Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync;
+
+/// A `Signer` (and, when the `async_signer` feature is enabled, `AsyncSigner`)
+/// implementation that delegates the raw signing operation to a user-supplied
+/// callback, while the certificate chain and other signing configuration are
+/// supplied directly.
+///
+/// This is useful when the private key is not directly accessible, for
+/// example when it is held in an HSM, a cloud KMS, or a hardware token: only
+/// the `sign` step needs to cross that boundary. `CallbackSigner` itself only
+/// runs the callback and reports the configured certificate chain, time
+/// authority URL, and OCSP response; COSE assembly, time stamping, and OCSP
+/// stapling are left to the c2pa signing code that consumes those values.
+pub struct CallbackSigner {
+    certs: Vec<Vec<u8>>,
+    alg: String,
+    reserve_size: usize,
+    callback: Box<CallbackFunc>,
+
+    time_authority_url: Option<String>,
+    ocsp_val: Option<Vec<u8>>,
+}
+
+impl CallbackSigner {
+    /// Create a `CallbackSigner` from a certificate chain, algorithm, reserve
+    /// size, and a callback that produces the raw signature over the bytes
+    /// it is given.
+    pub fn new<F>(certs: Vec<Vec<u8>>, alg: String, reserve_size: usize, callback: F) -> Self
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync + 'static,
+    {
+        Self {
+            certs,
+            alg,
+            reserve_size,
+            callback: Box::new(callback),
+            time_authority_url: None,
+            ocsp_val: None,
+        }
+    }
+
+    /// Set the URL of the time authority used to time stamp the signature.
+    pub fn with_time_authority_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.time_authority_url = Some(url.into());
+        self
+    }
+
+    /// Set the OCSP response bytes for the signing cert.
+    pub fn with_ocsp_val(mut self, ocsp_val: Vec<u8>) -> Self {
+        self.ocsp_val = Some(ocsp_val);
+        self
+    }
+}
+
+impl Signer for CallbackSigner {
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let signature = (self.callback)(data)?;
+
+        if signature.len() > self.reserve_size {
+            return Err(crate::Error::CoseSigboxTooSmall);
+        }
+
+        Ok(signature)
+    }
+
+    fn alg(&self) -> Option<String> {
+        Some(self.alg.clone())
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        Ok(self.certs.clone())
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        self.time_authority_url.clone()
+    }
+
+    fn ocsp_val(&self) -> Option<Vec<u8>> {
+        self.ocsp_val.clone()
+    }
+}
+
 #[cfg(feature = "async_signer")]
 use async_trait::async_trait;
 
@@ -109,6 +349,57 @@ pub trait AsyncSigner: Sync {
     /// Signing will fail if the result of the `sign` function is larger
     /// than this value.
     fn reserve_size(&self) -> usize;
+
+    /// Returns the algorithm of the Signer.
+    fn alg(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the certificates as a Vec containing a Vec of DER bytes for each certificate.
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        Ok(Vec::new())
+    }
+
+    /// URL for time authority to time stamp the signature
+    fn time_authority_url(&self) -> Option<String> {
+        None
+    }
+
+    /// OCSP response for the signing cert if available
+    /// This is the only C2PA supported cert revocation method.
+    /// By pre-querying the value for a your signing cert the value can
+    /// be cached taking pressure off of the CA (recommended by C2PA spec)
+    fn ocsp_val(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Returns `true` if `sign` expects to be given a message digest rather
+    /// than the raw to-be-signed bytes. See [`Signer::direct_cose_handling`].
+    fn direct_cose_handling(&self) -> bool {
+        false
+    }
+
+    /// The digest algorithm the signer expects to receive when
+    /// [`AsyncSigner::direct_cose_handling`] returns `true`. See
+    /// [`Signer::digest_alg`].
+    fn digest_alg(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The [`AsyncSigner`] counterpart to [`prepare_to_sign`]. It is a plain
+/// synchronous function, since preparing the bytes involves no signing call.
+#[cfg(feature = "async_signer")]
+pub fn prepare_to_sign_async(signer: &dyn AsyncSigner, data: &[u8]) -> Result<Vec<u8>> {
+    if !signer.direct_cose_handling() {
+        return Ok(data.to_vec());
+    }
+
+    let signing_alg = signer.alg().ok_or(crate::Error::UnsupportedType)?;
+    let digest_alg = signer.digest_alg().ok_or(crate::Error::UnsupportedType)?;
+    validate_digest_alg(&signing_alg, &digest_alg)?;
+
+    digest_with(&digest_alg, data)
 }
 
 /// The `AsyncPlaceholder` implementation provides a placeholder "async signer"
@@ -128,4 +419,809 @@ impl AsyncSigner for AsyncPlaceholder {
     fn reserve_size(&self) -> usize {
         128
     }
+}
+
+#[cfg(all(test, feature = "async_signer"))]
+mod async_signer_parity_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn async_placeholder_matches_sync_placeholder_defaults() {
+        let async_signer = AsyncPlaceholder {};
+        let sync_signer = Placeholder {};
+
+        assert_eq!(async_signer.alg(), sync_signer.alg());
+        assert_eq!(async_signer.certs().unwrap(), sync_signer.certs().unwrap());
+        assert_eq!(
+            async_signer.time_authority_url(),
+            sync_signer.time_authority_url()
+        );
+        assert_eq!(async_signer.ocsp_val(), sync_signer.ocsp_val());
+    }
+}
+
+#[cfg(feature = "async_signer")]
+#[async_trait]
+impl AsyncSigner for CallbackSigner {
+    async fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Signer::sign(self, data)
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size
+    }
+
+    fn alg(&self) -> Option<String> {
+        Signer::alg(self)
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        Signer::certs(self)
+    }
+
+    fn time_authority_url(&self) -> Option<String> {
+        Signer::time_authority_url(self)
+    }
+
+    fn ocsp_val(&self) -> Option<Vec<u8>> {
+        Signer::ocsp_val(self)
+    }
+}
+
+#[cfg(test)]
+mod callback_signer_tests {
+    use super::*;
+
+    fn test_signer(reserve_size: usize) -> CallbackSigner {
+        CallbackSigner::new(
+            vec![b"fake cert".to_vec()],
+            "Es256".to_string(),
+            reserve_size,
+            |data: &[u8]| Ok(data.to_vec()),
+        )
+        .with_time_authority_url("http://example.com/tsa")
+        .with_ocsp_val(b"fake ocsp".to_vec())
+    }
+
+    #[test]
+    fn sign_within_reserve_size_succeeds() {
+        let signer = test_signer(128);
+        assert_eq!(
+            Signer::sign(&signer, b"claim bytes").unwrap(),
+            b"claim bytes"
+        );
+    }
+
+    #[test]
+    fn sign_over_reserve_size_fails() {
+        let signer = test_signer(4);
+        assert!(Signer::sign(&signer, b"claim bytes").is_err());
+    }
+
+    #[test]
+    fn config_is_exposed_through_signer() {
+        let signer = test_signer(128);
+        assert_eq!(Signer::alg(&signer), Some("Es256".to_string()));
+        assert_eq!(Signer::certs(&signer).unwrap(), vec![b"fake cert".to_vec()]);
+        assert_eq!(
+            Signer::time_authority_url(&signer),
+            Some("http://example.com/tsa".to_string())
+        );
+        assert_eq!(Signer::ocsp_val(&signer), Some(b"fake ocsp".to_vec()));
+    }
+
+    #[cfg(feature = "async_signer")]
+    #[tokio::test]
+    async fn async_signer_matches_sync_config() {
+        let signer = test_signer(128);
+
+        assert_eq!(
+            AsyncSigner::sign(&signer, b"claim bytes").await.unwrap(),
+            b"claim bytes"
+        );
+        assert_eq!(AsyncSigner::alg(&signer), Signer::alg(&signer));
+        assert_eq!(
+            AsyncSigner::certs(&signer).unwrap(),
+            Signer::certs(&signer).unwrap()
+        );
+        assert_eq!(
+            AsyncSigner::time_authority_url(&signer),
+            Signer::time_authority_url(&signer)
+        );
+        assert_eq!(
+            AsyncSigner::ocsp_val(&signer),
+            Signer::ocsp_val(&signer)
+        );
+    }
+}
+
+/// The `RemoteSigner` trait is used in the signing process when the actual
+/// signing and COSE envelope assembly happens on a remote service, rather
+/// than being assembled locally from a raw signature.
+///
+/// Unlike [`Signer`] and [`AsyncSigner`], whose `sign` methods return only
+/// the raw signature bytes (which c2pa then wraps in a `CoseSign1`), a
+/// `RemoteSigner` returns the complete, already-wrapped COSE structure,
+/// meant to be embedded verbatim into the JUMBF `c2pa.signature` box.
+///
+/// This trait and [`RemoteSignerWrapper`] only provide that extension
+/// point; they don't hook into the manifest store's own COSE assembly.
+/// A caller wiring a `RemoteSigner` into the signing path is responsible
+/// for recognizing it (e.g. by downcasting to `RemoteSignerWrapper<T>`) and
+/// skipping its own assembly step when embedding the signature.
+#[cfg(feature = "async_signer")]
+#[async_trait]
+pub trait RemoteSigner: Sync {
+    /// Sends the claim bytes to the remote signing service and returns the
+    /// complete, COSE-wrapped signature to be embedded in the claim.
+    async fn sign_remote(&self, claim_bytes: &[u8]) -> Result<Vec<u8>>;
+
+    /// Returns the size in bytes of the largest possible expected COSE
+    /// signature. Signing will fail if the result of `sign_remote` is
+    /// larger than this value.
+    fn reserve_size(&self) -> usize;
+}
+
+/// Adapts a [`RemoteSigner`] so that it can be used anywhere an
+/// [`AsyncSigner`] is accepted. The resulting `sign` output is the complete
+/// COSE structure produced by the remote service, not a raw signature — see
+/// [`RemoteSigner`] for why embedding it correctly is the caller's
+/// responsibility.
+#[cfg(feature = "async_signer")]
+pub struct RemoteSignerWrapper<T: RemoteSigner>(pub T);
+
+#[cfg(feature = "async_signer")]
+#[async_trait]
+impl<T: RemoteSigner + Sync> AsyncSigner for RemoteSignerWrapper<T> {
+    async fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.0.sign_remote(data).await
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.0.reserve_size()
+    }
+}
+
+#[cfg(all(test, feature = "async_signer"))]
+mod remote_signer_tests {
+    use super::*;
+
+    struct FakeRemoteSigner;
+
+    #[async_trait]
+    impl RemoteSigner for FakeRemoteSigner {
+        async fn sign_remote(&self, claim_bytes: &[u8]) -> Result<Vec<u8>> {
+            Ok([b"cose:".as_slice(), claim_bytes].concat())
+        }
+
+        fn reserve_size(&self) -> usize {
+            256
+        }
+    }
+
+    #[tokio::test]
+    async fn wrapper_returns_remote_signer_output_verbatim() {
+        let wrapper = RemoteSignerWrapper(FakeRemoteSigner);
+
+        let signature = AsyncSigner::sign(&wrapper, b"claim bytes").await.unwrap();
+        assert_eq!(signature, b"cose:claim bytes");
+        assert_eq!(wrapper.reserve_size(), 256);
+    }
+}
+
+/// Returns a conservative upper bound on the raw signature size produced by
+/// `signing_alg`, used to derive [`Signer::reserve_size`] for adapters that
+/// don't otherwise have a natural reserve size to report.
+///
+/// Sized with headroom over the fixed raw-encoding length each algorithm
+/// actually produces (see [`expected_raw_signature_len`]), e.g. ES512's
+/// 132-byte raw signature needs more than the 128 bytes that's plenty for
+/// the smaller curves.
+#[cfg(feature = "rust_native_crypto")]
+fn default_reserve_size(signing_alg: &str) -> usize {
+    match signing_alg.to_ascii_uppercase().as_str() {
+        "ES256" | "ES384" | "ED25519" => 128,
+        "ES512" => 256,
+        "PS256" | "PS384" | "PS512" => 1024,
+        _ => 1024,
+    }
+}
+
+/// Returns the exact length, in bytes, of a raw (non-DER) signature for
+/// `signing_alg`, for algorithms where C2PA mandates the fixed-width raw
+/// `r || s` encoding rather than DER (`ECDSA`). Returns `None` for
+/// algorithms (e.g. RSA, Ed25519) where no such check is needed or the
+/// length isn't fixed by the curve alone.
+#[cfg(feature = "rust_native_crypto")]
+fn expected_raw_signature_len(signing_alg: &str) -> Option<usize> {
+    match signing_alg.to_ascii_uppercase().as_str() {
+        "ES256" => Some(64),
+        "ES384" => Some(96),
+        "ES512" => Some(132),
+        _ => None,
+    }
+}
+
+/// Adapts any key type from the [RustCrypto](https://github.com/RustCrypto)
+/// ecosystem that implements `signature::Signer<S>` (with `S: AsRef<[u8]>`)
+/// into a c2pa [`Signer`]. This covers ed25519, ECDSA (P-256/P-384), and RSA
+/// backends uniformly, including `no_std`/embedded implementations, without
+/// requiring a bespoke `Signer` impl for each one.
+///
+/// C2PA requires the fixed-width raw `r || s` encoding for ECDSA signatures,
+/// not the ASN.1 DER encoding some RustCrypto `Signature` types produce by
+/// default; `sign` rejects a signature whose length doesn't match the raw
+/// encoding for `alg` so a DER-producing key type fails loudly instead of
+/// emitting a signature verifiers will reject.
+///
+/// `S` is the concrete RustCrypto `Signature` type `K` produces (e.g.
+/// `p256::ecdsa::Signature`) and must be named explicitly at the call site,
+/// since it isn't otherwise determined by `K`, `alg`, or `certs` alone:
+/// `RustCryptoSigner::<MyKey, MySignature>::new(key, alg, certs)`.
+#[cfg(feature = "rust_native_crypto")]
+pub struct RustCryptoSigner<K, S> {
+    key: K,
+    alg: String,
+    certs: Vec<Vec<u8>>,
+    _signature: std::marker::PhantomData<S>,
+}
+
+#[cfg(feature = "rust_native_crypto")]
+impl<K, S> RustCryptoSigner<K, S> {
+    /// Create a `RustCryptoSigner` from a RustCrypto signing key, the
+    /// `SigningAlg` it implements, and its DER certificate chain.
+    pub fn new(key: K, alg: String, certs: Vec<Vec<u8>>) -> Self {
+        Self {
+            key,
+            alg,
+            certs,
+            _signature: std::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "rust_native_crypto")]
+impl<K, S> Signer for RustCryptoSigner<K, S>
+where
+    K: signature::Signer<S> + Sync,
+    S: AsRef<[u8]>,
+{
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let signature = self
+            .key
+            .try_sign(data)
+            .map_err(|e| crate::Error::CoseSignature(e.to_string()))?;
+        let signature = signature.as_ref().to_vec();
+
+        if let Some(expected_len) = expected_raw_signature_len(&self.alg) {
+            if signature.len() != expected_len {
+                return Err(crate::Error::CoseSignature(format!(
+                    "expected a {expected_len}-byte raw signature for {}, got {} bytes \
+                     (is the RustCrypto `Signature` type configured for DER output?)",
+                    self.alg,
+                    signature.len()
+                )));
+            }
+        }
+
+        Ok(signature)
+    }
+
+    fn alg(&self) -> Option<String> {
+        Some(self.alg.clone())
+    }
+
+    fn certs(&self) -> Result<Vec<Vec<u8>>> {
+        Ok(self.certs.clone())
+    }
+
+    fn reserve_size(&self) -> usize {
+        default_reserve_size(&self.alg)
+    }
+}
+
+#[cfg(all(test, feature = "rust_native_crypto"))]
+mod rust_crypto_signer_tests {
+    use super::*;
+
+    struct FixedSigKey(Vec<u8>);
+
+    impl signature::Signer<Vec<u8>> for FixedSigKey {
+        fn try_sign(&self, _msg: &[u8]) -> std::result::Result<Vec<u8>, signature::Error> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn default_reserve_size_covers_raw_signature_lengths() {
+        for (alg, raw_len) in [("ES256", 64), ("ES384", 96), ("ES512", 132)] {
+            assert!(
+                default_reserve_size(alg) > raw_len,
+                "{alg} reserve size must exceed its {raw_len}-byte raw signature"
+            );
+        }
+    }
+
+    #[test]
+    fn accepts_correctly_sized_raw_signature() {
+        let signer = RustCryptoSigner::<FixedSigKey, Vec<u8>>::new(
+            FixedSigKey(vec![0u8; 64]),
+            "ES256".to_string(),
+            vec![],
+        );
+        assert_eq!(signer.sign(b"data").unwrap().len(), 64);
+    }
+
+    #[test]
+    fn rejects_der_encoded_signature() {
+        // A DER-encoded ES256 signature is longer than the 64-byte raw
+        // encoding C2PA requires.
+        let signer = RustCryptoSigner::<FixedSigKey, Vec<u8>>::new(
+            FixedSigKey(vec![0u8; 70]),
+            "ES256".to_string(),
+            vec![],
+        );
+        assert!(signer.sign(b"data").is_err());
+    }
+}
+
+/// An ephemeral keypair generated in memory for a single keyless signing
+/// operation. The private key never leaves the process and is discarded once
+/// the operation completes.
+#[cfg(feature = "keyless_signer")]
+pub trait EphemeralKeyPair: Sync {
+    /// The public key, in the encoding the certificate authority expects.
+    fn public_key(&self) -> Vec<u8>;
+
+    /// Sign `data` with the ephemeral private key.
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// A short-lived signing certificate issued by the keyless certificate
+/// authority (e.g. Fulcio), binding an ephemeral public key to an identity
+/// asserted by an OIDC token.
+#[cfg(feature = "keyless_signer")]
+pub struct KeylessCertificate {
+    /// The issued certificate chain, in DER encoding.
+    pub chain: Vec<Vec<u8>>,
+
+    /// The Signed Certificate Timestamp proving the issuance was recorded in
+    /// the CA's certificate transparency log, to be verified against
+    /// [`KeylessCertificateAuthority::log_public_keys`] before the
+    /// certificate is trusted.
+    pub sct: Vec<u8>,
+}
+
+/// The certificate authority side of a keyless (Sigstore-style) signing flow,
+/// e.g. Fulcio.
+#[cfg(feature = "keyless_signer")]
+#[async_trait]
+pub trait KeylessCertificateAuthority: Sync {
+    /// Exchanges an OIDC identity token for a short-lived certificate binding
+    /// `public_key` to the token's identity.
+    async fn issue_certificate(
+        &self,
+        identity_token: &str,
+        public_key: &[u8],
+    ) -> Result<KeylessCertificate>;
+
+    /// The CA's known certificate transparency log public keys, used to
+    /// verify a certificate's SCT before trusting it.
+    fn log_public_keys(&self) -> Vec<Vec<u8>>;
+
+    /// Verifies `cert.sct` against [`KeylessCertificateAuthority::log_public_keys`].
+    /// [`KeylessSigner::sign_keyless`] calls this on every certificate
+    /// returned by `issue_certificate` and fails before signing if it
+    /// returns an error.
+    fn verify_sct(&self, cert: &KeylessCertificate) -> Result<()>;
+}
+
+/// The proof that a signature was recorded in an append-only transparency
+/// log (e.g. Rekor), allowing later offline verification that the signing
+/// event happened at the time claimed.
+#[cfg(feature = "keyless_signer")]
+pub struct InclusionProof {
+    /// The index of the logged entry within the transparency log.
+    pub log_index: u64,
+
+    /// The log's inclusion proof bytes for the entry at `log_index`.
+    pub proof: Vec<u8>,
+}
+
+/// The transparency log side of a keyless (Sigstore-style) signing flow,
+/// e.g. Rekor.
+#[cfg(feature = "keyless_signer")]
+#[async_trait]
+pub trait TransparencyLog: Sync {
+    /// Submits the certificate chain and signature to the log and returns
+    /// its inclusion proof.
+    async fn submit(&self, cert_chain: &[Vec<u8>], signature: &[u8]) -> Result<InclusionProof>;
+}
+
+/// Everything produced by a single [`KeylessSigner::sign_keyless`] call: the
+/// raw signature, the certificate issued for it, and the transparency log's
+/// inclusion proof for it. Keeping these scoped to the call (rather than
+/// cached on the signer) is what makes it safe to share one `KeylessSigner`
+/// across concurrent signs of different documents.
+#[cfg(feature = "keyless_signer")]
+pub struct KeylessSignature {
+    /// The raw signature over the data passed to `sign_keyless`.
+    pub signature: Vec<u8>,
+
+    /// The certificate issued for this signing operation.
+    pub certificate: KeylessCertificate,
+
+    /// The transparency log's inclusion proof for this signing operation.
+    pub inclusion_proof: InclusionProof,
+}
+
+/// A keyless (Sigstore-style) signer that avoids any long-lived private key.
+/// For each signing operation it generates an ephemeral keypair, exchanges
+/// the caller's OIDC identity token with a [`KeylessCertificateAuthority`]
+/// for a short-lived certificate binding the ephemeral public key to that
+/// identity, signs with the ephemeral key, and submits the signature to a
+/// [`TransparencyLog`] for accountability.
+///
+/// The issued certificate chain is only known after the CA round-trip, so
+/// [`AsyncSigner::reserve_size`] must either be computed after issuance or
+/// over-provisioned; this implementation over-provisions via
+/// `reserve_size_hint`.
+#[cfg(feature = "keyless_signer")]
+pub struct KeylessSigner<K, C, L> {
+    key_pair_factory: Box<dyn Fn() -> Result<K> + Send + Sync>,
+    ca: C,
+    log: L,
+    identity_token: String,
+    reserve_size_hint: usize,
+}
+
+#[cfg(feature = "keyless_signer")]
+impl<K, C, L> KeylessSigner<K, C, L>
+where
+    K: EphemeralKeyPair,
+    C: KeylessCertificateAuthority,
+    L: TransparencyLog,
+{
+    /// Create a `KeylessSigner` from a factory that generates a fresh
+    /// ephemeral keypair for each signing operation, the CA and
+    /// transparency log to use, and the caller's OIDC identity token.
+    ///
+    /// `reserve_size_hint` should over-provision for the certificate chain
+    /// size, since the actual chain length is not known until the CA issues
+    /// it.
+    pub fn new<F>(
+        key_pair_factory: F,
+        ca: C,
+        log: L,
+        identity_token: String,
+        reserve_size_hint: usize,
+    ) -> Self
+    where
+        F: Fn() -> Result<K> + Send + Sync + 'static,
+    {
+        Self {
+            key_pair_factory: Box::new(key_pair_factory),
+            ca,
+            log,
+            identity_token,
+            reserve_size_hint,
+        }
+    }
+}
+
+#[cfg(feature = "keyless_signer")]
+impl<K, C, L> KeylessSigner<K, C, L>
+where
+    K: EphemeralKeyPair + Send + Sync,
+    C: KeylessCertificateAuthority + Send + Sync,
+    L: TransparencyLog + Send + Sync,
+{
+    /// Runs a full keyless signing operation over `data` and returns the
+    /// signature together with the certificate and inclusion proof issued
+    /// for that specific call, so the caller can embed them alongside the
+    /// C2PA signature for later offline verification.
+    ///
+    /// Generates a brand new ephemeral keypair for this call via the
+    /// factory passed to [`KeylessSigner::new`] — the whole point of
+    /// keyless signing is that no key, ephemeral or otherwise, outlives a
+    /// single signing operation.
+    ///
+    /// Prefer this over the generic [`AsyncSigner::sign`] whenever the
+    /// certificate or inclusion proof is needed: `AsyncSigner::sign` can
+    /// only return the raw signature bytes, so it has nowhere to put them.
+    pub async fn sign_keyless(&self, data: &[u8]) -> Result<KeylessSignature> {
+        let key_pair = (self.key_pair_factory)()?;
+
+        let certificate = self
+            .ca
+            .issue_certificate(&self.identity_token, &key_pair.public_key())
+            .await?;
+        self.ca.verify_sct(&certificate)?;
+
+        let signature = key_pair.sign(data)?;
+
+        let inclusion_proof = self.log.submit(&certificate.chain, &signature).await?;
+
+        Ok(KeylessSignature {
+            signature,
+            certificate,
+            inclusion_proof,
+        })
+    }
+}
+
+#[cfg(feature = "keyless_signer")]
+#[async_trait]
+impl<K, C, L> AsyncSigner for KeylessSigner<K, C, L>
+where
+    K: EphemeralKeyPair + Send + Sync,
+    C: KeylessCertificateAuthority + Send + Sync,
+    L: TransparencyLog + Send + Sync,
+{
+    async fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.sign_keyless(data).await?.signature)
+    }
+
+    fn reserve_size(&self) -> usize {
+        self.reserve_size_hint
+    }
+
+    // `certs()` is intentionally left at the trait default (empty): the
+    // certificate chain is only known once a signing call has completed,
+    // and there is no per-call context here to hand it back through.
+    // Callers that need the chain should use `sign_keyless` directly.
+}
+
+#[cfg(all(test, feature = "keyless_signer"))]
+mod keyless_signer_tests {
+    use super::*;
+
+    /// A distinct ephemeral keypair per instance, so a factory that mints a
+    /// new one per call can be distinguished from one that reuses a single
+    /// instance.
+    struct CountingKeyPair {
+        id: u32,
+    }
+
+    impl EphemeralKeyPair for CountingKeyPair {
+        fn public_key(&self) -> Vec<u8> {
+            format!("ephemeral-key-{}", self.id).into_bytes()
+        }
+
+        fn sign(&self, data: &[u8]) -> Result<Vec<u8>> {
+            Ok([b"sig:".as_slice(), data].concat())
+        }
+    }
+
+    struct FakeCa;
+
+    #[async_trait]
+    impl KeylessCertificateAuthority for FakeCa {
+        async fn issue_certificate(
+            &self,
+            identity_token: &str,
+            public_key: &[u8],
+        ) -> Result<KeylessCertificate> {
+            Ok(KeylessCertificate {
+                chain: vec![[identity_token.as_bytes(), public_key].concat()],
+                sct: b"sct".to_vec(),
+            })
+        }
+
+        fn log_public_keys(&self) -> Vec<Vec<u8>> {
+            vec![b"log public key".to_vec()]
+        }
+
+        fn verify_sct(&self, cert: &KeylessCertificate) -> Result<()> {
+            if cert.sct == b"sct" {
+                Ok(())
+            } else {
+                Err(crate::Error::UnsupportedType)
+            }
+        }
+    }
+
+    struct FakeLog;
+
+    #[async_trait]
+    impl TransparencyLog for FakeLog {
+        async fn submit(&self, cert_chain: &[Vec<u8>], signature: &[u8]) -> Result<InclusionProof> {
+            Ok(InclusionProof {
+                log_index: 1,
+                proof: [cert_chain.concat(), signature.to_vec()].concat(),
+            })
+        }
+    }
+
+    fn test_signer() -> KeylessSigner<CountingKeyPair, FakeCa, FakeLog> {
+        let next_id = std::sync::atomic::AtomicU32::new(0);
+
+        KeylessSigner::new(
+            move || {
+                let id = next_id.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                Ok(CountingKeyPair { id })
+            },
+            FakeCa,
+            FakeLog,
+            "id-token".to_string(),
+            1024,
+        )
+    }
+
+    #[tokio::test]
+    async fn sign_keyless_generates_a_fresh_keypair_per_call() {
+        let signer = test_signer();
+
+        let first = signer.sign_keyless(b"document a").await.unwrap();
+        let second = signer.sign_keyless(b"document b").await.unwrap();
+
+        assert_eq!(first.signature, b"sig:document a");
+        assert_eq!(second.signature, b"sig:document b");
+        assert_ne!(
+            first.certificate.chain, second.certificate.chain,
+            "each sign_keyless call must use a fresh ephemeral keypair, not a cached one"
+        );
+    }
+
+    #[tokio::test]
+    async fn async_signer_sign_returns_raw_signature() {
+        let signer = test_signer();
+
+        let signature = AsyncSigner::sign(&signer, b"document").await.unwrap();
+        assert_eq!(signature, b"sig:document");
+    }
+}
+
+/// The JUMBF assertion label used for a CAWG-style identity assertion
+/// binding a W3C Verifiable Credential to a manifest, independent of the
+/// manifest's own claim signature.
+#[cfg(feature = "identity_assertion")]
+pub const IDENTITY_ASSERTION_LABEL: &str = "cawg.identity";
+
+/// A signed identity assertion: a canonicalized W3C Verifiable Credential
+/// together with the signature over it, suitable for embedding as a named
+/// assertion (see [`IDENTITY_ASSERTION_LABEL`]) alongside the manifest's
+/// claim signature.
+///
+/// Downstream verifiers can extract and validate `payload`/`signature`
+/// independently of whether the active manifest signature itself verifies.
+#[cfg(feature = "identity_assertion")]
+pub struct IdentityAssertion {
+    /// The assertion label this credential should be embedded under.
+    pub label: String,
+
+    /// The canonicalized Verifiable Credential bytes that were signed.
+    pub payload: Vec<u8>,
+
+    /// The signature over `payload`, produced by the [`Signer`] passed to
+    /// [`sign_identity_assertion`]. These are the raw signature bytes; they
+    /// are not wrapped in a `CoseSign1`, and nothing here embeds the
+    /// assertion in a manifest.
+    pub signature: Vec<u8>,
+}
+
+/// Canonicalizes a W3C Verifiable Credential (JSON-LD) per the [JSON
+/// Canonicalization Scheme (RFC 8785)](https://www.rfc-editor.org/rfc/rfc8785):
+/// object keys are sorted by UTF-16 code unit, and the result is serialized
+/// with no insignificant whitespace, so semantically identical credentials
+/// produce identical bytes prior to signing.
+///
+/// This covers the part of RFC 8785 that matters for typical VC payloads
+/// (key ordering; `serde_json`'s compact number/string formatting already
+/// matches the ECMA-262 rules JCS specifies for the integers and strings
+/// such credentials are made of). It does not perform JSON-LD RDF dataset
+/// normalization (URDNA2015); credentials whose equivalence depends on that
+/// (e.g. differing key order *within* an unordered `@graph`) must be
+/// RDF-normalized before calling this.
+#[cfg(feature = "identity_assertion")]
+fn canonicalize_credential(credential: &serde_json::Value) -> Result<Vec<u8>> {
+    fn sort_keys(value: &serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut sorted = serde_json::Map::new();
+                let mut keys: Vec<&String> = map.keys().collect();
+                keys.sort_by(|a, b| a.encode_utf16().cmp(b.encode_utf16()));
+                for key in keys {
+                    sorted.insert(key.clone(), sort_keys(&map[key]));
+                }
+                serde_json::Value::Object(sorted)
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.iter().map(sort_keys).collect())
+            }
+            other => other.clone(),
+        }
+    }
+
+    serde_json::to_vec(&sort_keys(credential)).map_err(crate::Error::JsonError)
+}
+
+/// Canonicalizes a W3C Verifiable Credential and signs it with `signer`,
+/// producing an [`IdentityAssertion`] that can be embedded as a named
+/// assertion in addition to the manifest's normal claim signature,
+/// so a creator can bind a cryptographic identity claim independently of
+/// the active-manifest signature.
+#[cfg(feature = "identity_assertion")]
+pub fn sign_identity_assertion(
+    signer: &dyn Signer,
+    credential: &serde_json::Value,
+) -> Result<IdentityAssertion> {
+    let payload = canonicalize_credential(credential)?;
+    let signature = signer.sign(&payload)?;
+
+    Ok(IdentityAssertion {
+        label: IDENTITY_ASSERTION_LABEL.to_string(),
+        payload,
+        signature,
+    })
+}
+
+/// The [`AsyncSigner`] counterpart to [`sign_identity_assertion`], for
+/// identities signed by an async signer — in particular, `c2pa`'s own
+/// [`KeylessSigner`], which only implements `AsyncSigner`.
+#[cfg(all(feature = "identity_assertion", feature = "async_signer"))]
+pub async fn sign_identity_assertion_async(
+    signer: &dyn AsyncSigner,
+    credential: &serde_json::Value,
+) -> Result<IdentityAssertion> {
+    let payload = canonicalize_credential(credential)?;
+    let signature = signer.sign(&payload).await?;
+
+    Ok(IdentityAssertion {
+        label: IDENTITY_ASSERTION_LABEL.to_string(),
+        payload,
+        signature,
+    })
+}
+
+#[cfg(test)]
+mod identity_assertion_tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn canonicalize_is_order_independent() {
+        let a = json!({"b": 1, "a": 2, "c": {"y": 1, "x": 2}});
+        let b = json!({"a": 2, "c": {"x": 2, "y": 1}, "b": 1});
+
+        assert_eq!(
+            canonicalize_credential(&a).unwrap(),
+            canonicalize_credential(&b).unwrap()
+        );
+    }
+
+    #[test]
+    fn canonicalize_sorts_keys_by_utf16_code_unit() {
+        let value = json!({"b": 1, "a": 2});
+        let canonical = canonicalize_credential(&value).unwrap();
+
+        assert_eq!(canonical, br#"{"a":2,"b":1}"#);
+    }
+
+    #[test]
+    fn sign_identity_assertion_labels_and_signs_payload() {
+        let signer = Placeholder {};
+        let credential = json!({"id": "did:example:123"});
+
+        let assertion = sign_identity_assertion(&signer, &credential).unwrap();
+
+        assert_eq!(assertion.label, IDENTITY_ASSERTION_LABEL);
+        assert_eq!(assertion.payload, canonicalize_credential(&credential).unwrap());
+        assert_eq!(assertion.signature, signer.sign(&assertion.payload).unwrap());
+    }
+
+    #[cfg(feature = "async_signer")]
+    #[tokio::test]
+    async fn sign_identity_assertion_async_matches_sync_payload() {
+        let signer = AsyncPlaceholder {};
+        let credential = json!({"id": "did:example:123"});
+
+        let assertion = sign_identity_assertion_async(&signer, &credential)
+            .await
+            .unwrap();
+
+        assert_eq!(assertion.label, IDENTITY_ASSERTION_LABEL);
+        assert_eq!(assertion.payload, canonicalize_credential(&credential).unwrap());
+    }
 }
\ No newline at end of file